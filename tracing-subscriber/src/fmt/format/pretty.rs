@@ -1,11 +1,14 @@
 use super::*;
+use super::style::{Ansi, Palette, StyleRemap};
 use crate::{
     field::{VisitFmt, VisitOutput},
     fmt::fmt_subscriber::{FmtContext, FormattedFields},
     registry::LookupSpan,
 };
 
+use std::collections::HashSet;
 use std::fmt;
+use std::sync::Arc;
 use tracing_core::{
     field::{self, Field},
     Collect, Event,
@@ -96,6 +99,123 @@ use tracing_log::NormalizeEvent;
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Pretty {
     display_location: bool,
+    error_chain: ErrorStyle,
+    values: ValuePalette,
+    spans: SpanStyle,
+    palette: Palette,
+    filter: FieldFilter,
+    style_remap: Option<StyleRemap>,
+}
+
+/// Configures how the event's span scope is rendered by the [`Pretty`]
+/// formatter.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SpanStyle {
+    /// Render the scope as a flat sequence of `in target::name with fields`
+    /// lines, in innermost-to-outermost order.
+    ///
+    /// This is the default.
+    Flat,
+    /// Render the scope as an indented tree, outermost span first,
+    /// increasing indentation by `indent` spaces per nesting level and
+    /// connecting each level with a light box-drawing connector (`└─`/`├─`)
+    /// so the caller hierarchy is visually obvious.
+    Tree {
+        /// The number of additional spaces of indentation per nesting
+        /// level.
+        indent: usize,
+    },
+}
+
+/// Configures how the cause chain of a recorded [`Error`] is rendered by the
+/// [`Pretty`] formatter.
+///
+/// [`Error`]: std::error::Error
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorStyle {
+    /// Render the whole cause chain on the event's line, as a
+    /// comma-separated list (e.g. `error.sources: [out of space, out of
+    /// cash]`).
+    ///
+    /// This is the default.
+    Inline,
+    /// Render each cause in the chain on its own indented, numbered line
+    /// beneath the event (e.g. `error.1: out of space`, `error.2: out of
+    /// cash`), aligned with the `at`/`in` continuation lines. Each line is
+    /// prefixed with the name of the field it came from, so multiple
+    /// `error`-typed fields on one event don't produce an ambiguous,
+    /// interleaved chain.
+    Indented,
+}
+
+/// A color used to style an event's field values.
+///
+/// The basic ANSI colors degrade sensibly on terminals that only support
+/// them. For terminals with richer color support, [`Color::Ansi256`] and
+/// [`Color::Rgb`] select an xterm 256-color palette index or an arbitrary
+/// 24-bit RGB value, respectively; themed output that the basic colors
+/// can't express can use those instead.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Purple,
+    Cyan,
+    White,
+    /// An xterm 256-color palette index.
+    Ansi256(u8),
+    /// An arbitrary 24-bit RGB color.
+    Rgb(u8, u8, u8),
+}
+
+/// Per-value-kind styles used by the [`Pretty`] and [`PrettyFields`]
+/// formatters to color field values based on their concrete type.
+///
+/// By default, every kind of value uses the formatter's ambient style (the
+/// same behavior as before this palette existed). Use the builder methods
+/// below to assign a distinct color to strings, numbers, and booleans, so
+/// that the output can be scanned at a glance.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ValuePalette {
+    string: Option<Color>,
+    number: Option<Color>,
+    boolean: Option<Color>,
+}
+
+impl ValuePalette {
+    /// Returns a new `ValuePalette` that uses the formatter's default style
+    /// for every kind of value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color used for string values.
+    pub fn string(self, color: Color) -> Self {
+        Self {
+            string: Some(color),
+            ..self
+        }
+    }
+
+    /// Sets the color used for numeric values (`i64`, `u64`, and `f64`).
+    pub fn number(self, color: Color) -> Self {
+        Self {
+            number: Some(color),
+            ..self
+        }
+    }
+
+    /// Sets the color used for boolean values.
+    pub fn boolean(self, color: Color) -> Self {
+        Self {
+            boolean: Some(color),
+            ..self
+        }
+    }
 }
 
 /// The [visitor] produced by [`Pretty`]'s [`MakeVisitor`] implementation.
@@ -107,6 +227,17 @@ pub struct PrettyVisitor<'a> {
     writer: Writer<'a>,
     is_empty: bool,
     result: fmt::Result,
+    error_chain: ErrorStyle,
+    // Causes collected by `record_error` when `error_chain` is
+    // `ErrorStyle::Indented`, flushed as indented, numbered lines once all
+    // fields have been recorded. Each line is already prefixed with the
+    // name of the field it came from, so multiple `error`-typed fields on
+    // the same event don't produce an ambiguous, interleaved chain.
+    chain: Vec<String>,
+    values: ValuePalette,
+    field_key_color: Option<Color>,
+    filter: FieldFilter,
+    style_remap: Option<StyleRemap>,
 }
 
 /// An excessively pretty, human-readable [`MakeVisitor`] implementation.
@@ -125,6 +256,34 @@ pub struct PrettyFields {
     // TODO: when `PrettyFields::with_ansi` is removed, we can get rid
     // of this entirely.
     ansi: Option<bool>,
+
+    /// An [`Ansi`] mode to resolve against the `Writer`'s own ANSI setting,
+    /// overriding it. See [`PrettyFields::with_ansi_mode`].
+    ansi_mode: Option<Ansi>,
+
+    /// The per-value-kind color palette used to style field values. See
+    /// [`Pretty::with_value_palette`].
+    values: ValuePalette,
+
+    /// Which fields to redact or drop before formatting. See
+    /// [`PrettyFields::redact`] and [`PrettyFields::only`].
+    filter: FieldFilter,
+
+    /// Style remappings applied to string field values. See
+    /// [`Pretty::with_style_remap`].
+    style_remap: Option<StyleRemap>,
+}
+
+/// Which fields a [`Pretty`] or [`PrettyFields`] formatter shows, keyed by
+/// field name.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum FieldFilter {
+    /// Show every field.
+    None,
+    /// Replace the value of the named fields with `***`.
+    Redact(Arc<HashSet<String>>),
+    /// Only show the named fields; drop everything else.
+    Only(Arc<HashSet<String>>),
 }
 
 // === impl Pretty ===
@@ -133,6 +292,12 @@ impl Default for Pretty {
     fn default() -> Self {
         Self {
             display_location: true,
+            error_chain: ErrorStyle::Inline,
+            values: ValuePalette::new(),
+            spans: SpanStyle::Flat,
+            palette: Palette::default(),
+            filter: FieldFilter::None,
+            style_remap: None,
         }
     }
 }
@@ -151,6 +316,86 @@ impl Pretty {
             ..self
         }
     }
+
+    /// Sets how the cause chain of recorded errors is rendered.
+    ///
+    /// By default ([`ErrorStyle::Inline`]), the whole chain is rendered on
+    /// the event's line as a comma-separated list. Selecting
+    /// [`ErrorStyle::Indented`] instead renders each cause on its own
+    /// indented, numbered line beneath the event.
+    pub fn with_error_chain(self, error_chain: ErrorStyle) -> Self {
+        Self {
+            error_chain,
+            ..self
+        }
+    }
+
+    /// Sets the per-value-kind color palette used when styling field values.
+    ///
+    /// This defaults to [`ValuePalette::new()`], which leaves every kind of
+    /// value styled the same way as before this palette existed.
+    pub fn with_value_palette(self, values: ValuePalette) -> Self {
+        Self { values, ..self }
+    }
+
+    /// Sets how the event's span scope is rendered.
+    ///
+    /// By default ([`SpanStyle::Flat`]), the scope is rendered as a flat
+    /// list of `in target::name with fields` lines, innermost span first.
+    /// Selecting [`SpanStyle::Tree`] instead renders the scope as an
+    /// indented tree, outermost span first.
+    pub fn with_span_style(self, spans: SpanStyle) -> Self {
+        Self { spans, ..self }
+    }
+
+    /// Sets the color palette used for the level (and other semantic
+    /// roles), overriding the built-in colors.
+    ///
+    /// This lets users on light terminals, or with color-vision
+    /// constraints, remap the whole scheme. Defaults to [`Palette::default`].
+    pub fn with_palette(self, palette: Palette) -> Self {
+        Self { palette, ..self }
+    }
+
+    /// Sets a table of style remappings applied to string field values that
+    /// already contain ANSI escape sequences (for example, strings logged
+    /// by a library that colors its own output), so that they match this
+    /// subscriber's theme instead of whatever they were logged with.
+    pub fn with_style_remap(self, style_remap: StyleRemap) -> Self {
+        Self {
+            style_remap: Some(style_remap),
+            ..self
+        }
+    }
+
+    /// Replaces the value of the named fields with `***` before formatting.
+    ///
+    /// This is useful for avoiding leaking secrets (such as passwords or
+    /// auth tokens) in human-readable logs. See [`PrettyFields::redact`].
+    pub fn redact<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            filter: FieldFilter::Redact(Arc::new(names.into_iter().map(Into::into).collect())),
+            ..self
+        }
+    }
+
+    /// Only shows the named fields, dropping every other field.
+    ///
+    /// See [`PrettyFields::only`].
+    pub fn only<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            filter: FieldFilter::Only(Arc::new(names.into_iter().map(Into::into).collect())),
+            ..self
+        }
+    }
 }
 
 impl<C, N, T> FormatEvent<C, N> for Format<Pretty, T>
@@ -176,7 +421,7 @@ where
         self.format_timestamp(&mut writer)?;
 
         let style = if self.display_level {
-            writer.style.level_color(meta.level())
+            writer.style.level_color(meta.level(), &self.format.palette)
         } else {
             writer.style
         };
@@ -186,10 +431,14 @@ where
         }
 
         if self.display_target {
+            let target_style = match self.format.palette.target_color() {
+                Some(color) => style.fg(color).bold(),
+                None => style.bold(),
+            };
             write!(
                 writer,
                 "{}{}",
-                style.bold().paint(meta.target()),
+                target_style.paint(meta.target()),
                 style.paint(":")
             )?;
         }
@@ -212,7 +461,12 @@ where
 
         writer.write_char(' ')?;
 
-        let mut v = PrettyVisitor::new(writer.by_styled_ref(style), true);
+        let mut v = PrettyVisitor::new(writer.by_styled_ref(style), true)
+            .with_error_chain(self.format.error_chain)
+            .with_value_palette(self.format.values)
+            .with_field_key_color(self.format.palette.field_key_color())
+            .with_field_filter(self.format.filter.clone())
+            .with_style_remap(self.format.style_remap.clone());
         event.record(&mut v);
         v.finish()?;
         writer.write_char('\n')?;
@@ -253,6 +507,10 @@ where
         }
 
         let bold = writer.style.bold();
+        let span_name_style = match self.format.palette.span_name_color() {
+            Some(color) => bold.fg(color),
+            None => bold,
+        };
         let span = event
             .parent()
             .and_then(|id| ctx.span(id))
@@ -260,33 +518,75 @@ where
 
         let scope = span.into_iter().flat_map(|span| span.scope());
 
-        for span in scope {
-            let meta = span.metadata();
-            if self.display_target {
-                write!(
-                    writer,
-                    "    {} {}::{}",
-                    dimmed_italic.paint("in"),
-                    meta.target(),
-                    bold.paint(meta.name()),
-                )?;
-            } else {
-                write!(
-                    writer,
-                    "    {} {}",
-                    dimmed_italic.paint("in"),
-                    bold.paint(meta.name()),
-                )?;
+        match &self.format.spans {
+            SpanStyle::Flat => {
+                for span in scope {
+                    let meta = span.metadata();
+                    if self.display_target {
+                        write!(
+                            writer,
+                            "    {} {}::{}",
+                            dimmed_italic.paint("in"),
+                            meta.target(),
+                            span_name_style.paint(meta.name()),
+                        )?;
+                    } else {
+                        write!(
+                            writer,
+                            "    {} {}",
+                            dimmed_italic.paint("in"),
+                            span_name_style.paint(meta.name()),
+                        )?;
+                    }
+
+                    let ext = span.extensions();
+                    let fields = &ext
+                        .get::<FormattedFields<N>>()
+                        .expect("Unable to find FormattedFields in extensions; this is a bug");
+                    if !fields.is_empty() {
+                        write!(writer, " {} {}", dimmed_italic.paint("with"), fields)?;
+                    }
+                    writer.write_char('\n')?;
+                }
             }
+            SpanStyle::Tree { indent } => {
+                let spans: Vec<_> = scope.collect();
+                let depth = spans.len();
+                for (i, span) in spans.into_iter().rev().enumerate() {
+                    let meta = span.metadata();
+                    let connector = if i + 1 == depth { "└─" } else { "├─" };
+                    let pad = " ".repeat(*indent * i);
+                    if self.display_target {
+                        write!(
+                            writer,
+                            "    {}{} {} {}::{}",
+                            pad,
+                            dimmed_italic.paint(connector),
+                            dimmed_italic.paint("in"),
+                            meta.target(),
+                            span_name_style.paint(meta.name()),
+                        )?;
+                    } else {
+                        write!(
+                            writer,
+                            "    {}{} {} {}",
+                            pad,
+                            dimmed_italic.paint(connector),
+                            dimmed_italic.paint("in"),
+                            span_name_style.paint(meta.name()),
+                        )?;
+                    }
 
-            let ext = span.extensions();
-            let fields = &ext
-                .get::<FormattedFields<N>>()
-                .expect("Unable to find FormattedFields in extensions; this is a bug");
-            if !fields.is_empty() {
-                write!(writer, " {} {}", dimmed_italic.paint("with"), fields)?;
+                    let ext = span.extensions();
+                    let fields = &ext
+                        .get::<FormattedFields<N>>()
+                        .expect("Unable to find FormattedFields in extensions; this is a bug");
+                    if !fields.is_empty() {
+                        write!(writer, " {} {}", dimmed_italic.paint("with"), fields)?;
+                    }
+                    writer.write_char('\n')?;
+                }
             }
-            writer.write_char('\n')?;
         }
 
         writer.write_char('\n')
@@ -295,7 +595,12 @@ where
 
 impl<'writer> FormatFields<'writer> for Pretty {
     fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
-        let mut v = PrettyVisitor::new(writer, true);
+        let mut v = PrettyVisitor::new(writer, true)
+            .with_error_chain(self.error_chain)
+            .with_value_palette(self.values)
+            .with_field_key_color(self.palette.field_key_color())
+            .with_field_filter(self.filter.clone())
+            .with_style_remap(self.style_remap.clone());
         fields.record(&mut v);
         v.finish()
     }
@@ -307,7 +612,12 @@ impl<'writer> FormatFields<'writer> for Pretty {
     ) -> fmt::Result {
         let empty = current.is_empty();
         let writer = current.as_writer();
-        let mut v = PrettyVisitor::new(writer, empty);
+        let mut v = PrettyVisitor::new(writer, empty)
+            .with_error_chain(self.error_chain)
+            .with_value_palette(self.values)
+            .with_field_key_color(self.palette.field_key_color())
+            .with_field_filter(self.filter.clone())
+            .with_style_remap(self.style_remap.clone());
         fields.record(&mut v);
         v.finish()
     }
@@ -327,7 +637,42 @@ impl PrettyFields {
         // By default, don't override the `Writer`'s ANSI colors
         // configuration. We'll only do this if the user calls the
         // deprecated `PrettyFields::with_ansi` method.
-        Self { ansi: None }
+        Self {
+            ansi: None,
+            ansi_mode: None,
+            values: ValuePalette::new(),
+            filter: FieldFilter::None,
+            style_remap: None,
+        }
+    }
+
+    /// Returns a new [`PrettyFields`] that replaces the value of the named
+    /// fields with `***` before formatting.
+    ///
+    /// This is useful for avoiding leaking secrets (such as passwords or
+    /// auth tokens) in human-readable logs.
+    pub fn redact<I>(names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            filter: FieldFilter::Redact(Arc::new(names.into_iter().map(Into::into).collect())),
+            ..Self::new()
+        }
+    }
+
+    /// Returns a new [`PrettyFields`] that only shows the named fields,
+    /// dropping every other field.
+    pub fn only<I>(names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            filter: FieldFilter::Only(Arc::new(names.into_iter().map(Into::into).collect())),
+            ..Self::new()
+        }
     }
 
     /// Enable ANSI encoding for formatted fields.
@@ -341,6 +686,35 @@ impl PrettyFields {
             ..self
         }
     }
+
+    /// Overrides whether ANSI escape codes are emitted, resolving an
+    /// [`Ansi`] mode against the `Writer`'s own ANSI setting rather than
+    /// forcing a fixed `bool`.
+    ///
+    /// [`Ansi::Auto`] keeps deferring to the `Writer`'s existing decision
+    /// (as set up by the subscriber's `MakeWriter`), but additionally
+    /// honors the `NO_COLOR`/`CLICOLOR` environment variables; see [`Ansi`].
+    pub fn with_ansi_mode(self, ansi_mode: Ansi) -> Self {
+        Self {
+            ansi_mode: Some(ansi_mode),
+            ..self
+        }
+    }
+
+    /// Sets the per-value-kind color palette used when styling field values.
+    /// See [`Pretty::with_value_palette`].
+    pub fn with_value_palette(self, values: ValuePalette) -> Self {
+        Self { values, ..self }
+    }
+
+    /// Sets a table of style remappings applied to string field values. See
+    /// [`Pretty::with_style_remap`].
+    pub fn with_style_remap(self, style_remap: StyleRemap) -> Self {
+        Self {
+            style_remap: Some(style_remap),
+            ..self
+        }
+    }
 }
 
 impl<'a> MakeVisitor<Writer<'a>> for PrettyFields {
@@ -351,7 +725,14 @@ impl<'a> MakeVisitor<Writer<'a>> for PrettyFields {
         if let Some(ansi) = self.ansi {
             target = target.with_ansi(ansi);
         }
+        if let Some(mode) = self.ansi_mode {
+            let is_ansi = Style::from_ansi_mode(mode, target.style.is_ansi()).is_ansi();
+            target = target.with_ansi(is_ansi);
+        }
         PrettyVisitor::new(target, true)
+            .with_value_palette(self.values)
+            .with_field_filter(self.filter.clone())
+            .with_style_remap(self.style_remap.clone())
     }
 }
 
@@ -369,6 +750,69 @@ impl<'a> PrettyVisitor<'a> {
             writer,
             is_empty,
             result: Ok(()),
+            error_chain: ErrorStyle::Inline,
+            chain: Vec::new(),
+            values: ValuePalette::new(),
+            field_key_color: None,
+            filter: FieldFilter::None,
+            style_remap: None,
+        }
+    }
+
+    /// Sets how error cause chains recorded by this visitor are rendered.
+    pub(crate) fn with_error_chain(self, error_chain: ErrorStyle) -> Self {
+        Self {
+            error_chain,
+            ..self
+        }
+    }
+
+    /// Sets the per-value-kind color palette used when styling field values.
+    pub(crate) fn with_value_palette(self, values: ValuePalette) -> Self {
+        Self { values, ..self }
+    }
+
+    /// Sets the color used for field keys, from [`Palette::field_key_color`].
+    pub(crate) fn with_field_key_color(self, field_key_color: Option<Color>) -> Self {
+        Self {
+            field_key_color,
+            ..self
+        }
+    }
+
+    /// Returns the style to use for a value of the given color, falling back
+    /// to the visitor's ambient style if no color is configured for that
+    /// kind of value.
+    fn value_style(&self, color: Option<Color>) -> Style {
+        match color {
+            Some(color) => self.style().fg(color),
+            None => self.style(),
+        }
+    }
+
+    /// Sets which fields are redacted or dropped before formatting.
+    pub(crate) fn with_field_filter(self, filter: FieldFilter) -> Self {
+        Self { filter, ..self }
+    }
+
+    fn is_redacted(&self, name: &str) -> bool {
+        let name = name.strip_prefix("r#").unwrap_or(name);
+        matches!(&self.filter, FieldFilter::Redact(names) if names.contains(name))
+    }
+
+    fn is_shown(&self, name: &str) -> bool {
+        let name = name.strip_prefix("r#").unwrap_or(name);
+        match &self.filter {
+            FieldFilter::Only(names) => names.contains(name),
+            _ => true,
+        }
+    }
+
+    /// Sets the style remappings applied to string field values.
+    pub(crate) fn with_style_remap(self, style_remap: Option<StyleRemap>) -> Self {
+        Self {
+            style_remap,
+            ..self
         }
     }
 
@@ -389,20 +833,39 @@ impl<'a> PrettyVisitor<'a> {
     #[must_use]
     fn record_debug_impl(&mut self, field: &Field, styled_value: &dyn fmt::Debug) -> fmt::Result {
         let bold = self.style().bold();
+        let key_style = match self.field_key_color {
+            Some(color) => bold.fg(color),
+            None => bold,
+        };
         match field.name() {
+            // Skip fields that are actually log metadata that have already been handled
+            #[cfg(feature = "tracing-log")]
+            name if name.starts_with("log.") => Ok(()),
+            "message" if self.is_redacted("message") => {
+                self.write_padding()?;
+                write!(self.writer, "{}", bold.paint("***"))
+            }
+            name if self.is_redacted(name) => {
+                self.write_padding()?;
+                write!(
+                    self.writer,
+                    "{}{} {}",
+                    key_style.paint(name.strip_prefix("r#").unwrap_or(name)),
+                    bold.paint(":"),
+                    bold.paint("***"),
+                )
+            }
+            name if !self.is_shown(name) => Ok(()),
             "message" => {
                 self.write_padding()?;
                 write!(self.writer, "{:?}", styled_value)
             }
-            // Skip fields that are actually log metadata that have already been handled
-            #[cfg(feature = "tracing-log")]
-            name if name.starts_with("log.") => Ok(()),
             name if name.starts_with("r#") => {
                 self.write_padding()?;
                 write!(
                     self.writer,
                     "{}{} {:?}",
-                    bold.paint(&name[2..]),
+                    key_style.paint(&name[2..]),
                     bold.paint(":"),
                     styled_value
                 )
@@ -412,7 +875,7 @@ impl<'a> PrettyVisitor<'a> {
                 write!(
                     self.writer,
                     "{}{} {:?}",
-                    bold.paint(name),
+                    key_style.paint(name),
                     bold.paint(":"),
                     styled_value
                 )
@@ -427,35 +890,103 @@ impl<'a> field::Visit for PrettyVisitor<'a> {
             return;
         }
 
+        let style = self.value_style(self.values.string);
+        let remapped;
+        let value = match &self.style_remap {
+            Some(remap) => {
+                remapped = remap.apply(value);
+                remapped.as_str()
+            }
+            None => value,
+        };
         self.result = if field.name() == "message" {
-            self.record_debug_impl(field, &format_args!("{}", self.style().paint(value)))
+            self.record_debug_impl(field, &format_args!("{}", style.paint(value)))
         } else {
-            self.record_debug_impl(field, &self.style().paint(value))
+            self.record_debug_impl(field, &style.paint(value))
         }
     }
 
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        if self.result.is_err() {
+            return;
+        }
+        let style = self.value_style(self.values.number);
+        self.result = self.record_debug_impl(field, &style.paint(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if self.result.is_err() {
+            return;
+        }
+        let style = self.value_style(self.values.number);
+        self.result = self.record_debug_impl(field, &style.paint(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if self.result.is_err() {
+            return;
+        }
+        let style = self.value_style(self.values.number);
+        self.result = self.record_debug_impl(field, &style.paint(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if self.result.is_err() {
+            return;
+        }
+        let style = self.value_style(self.values.boolean);
+        self.result = self.record_debug_impl(field, &style.paint(value));
+    }
+
     fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
         if self.result.is_err() {
             return;
         }
 
         let style = self.style();
-        self.result = if let Some(source) = value.source() {
-            let bold = style.bold();
-            self.record_debug_impl(
-                field,
-                &format_args!(
-                    "{}{} {}{}{} {}",
-                    style.paint(value),
-                    style.paint(","),
-                    bold.paint(field),
-                    bold.paint(".sources"),
-                    style.paint(":"),
-                    style.paint(ErrorSourceList(source))
-                ),
-            )
-        } else {
-            self.record_debug_impl(field, &format_args!("{}", style.paint(value)))
+        match (self.error_chain, value.source()) {
+            (ErrorStyle::Inline, Some(source)) => {
+                let bold = style.bold();
+                self.result = self.record_debug_impl(
+                    field,
+                    &format_args!(
+                        "{}{} {}{}{} {}",
+                        style.paint(value),
+                        style.paint(","),
+                        bold.paint(field),
+                        bold.paint(".sources"),
+                        style.paint(":"),
+                        style.paint(ErrorSourceList(source))
+                    ),
+                )
+            }
+            (ErrorStyle::Indented, Some(mut source)) => {
+                self.result =
+                    self.record_debug_impl(field, &format_args!("{}", style.paint(value)));
+                // The error itself was already redacted or dropped by
+                // `record_debug_impl` above; honor the same filter for the
+                // chain lines it's about to queue, or they'd leak the cause
+                // chain of a field that's supposed to be hidden.
+                if self.is_redacted(field.name()) || !self.is_shown(field.name()) {
+                    return;
+                }
+                // Tag each line with the field's name so that an event with
+                // more than one `error`-typed field doesn't interleave their
+                // chains under ambiguous, duplicate `1:`/`2:` labels.
+                let mut depth = 1;
+                loop {
+                    self.chain
+                        .push(format!("{}.{}: {}", field.name(), depth, source));
+                    source = match source.source() {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    depth += 1;
+                }
+            }
+            (_, None) => {
+                self.result = self.record_debug_impl(field, &format_args!("{}", style.paint(value)))
+            }
         }
     }
 
@@ -468,8 +999,13 @@ impl<'a> field::Visit for PrettyVisitor<'a> {
 }
 
 impl<'a> VisitOutput<fmt::Result> for PrettyVisitor<'a> {
-    fn finish(self) -> fmt::Result {
-        self.result
+    fn finish(mut self) -> fmt::Result {
+        self.result?;
+        let dimmed = self.style().dimmed();
+        for cause in &self.chain {
+            write!(self.writer, "\n    {}", dimmed.paint(cause))?;
+        }
+        Ok(())
     }
 }
 
@@ -1,11 +1,401 @@
 #[cfg(feature = "ansi")]
-use owo_colors::{Style as AnsiStyle, Styled};
+use owo_colors::{Rgb, Style as AnsiStyle, Styled, XtermColors};
 use tracing_core::Level;
 
+use super::pretty::Color;
+
 pub(crate) trait StylePainter {
     fn paint<T>(&self, d: T) -> Styled<T>;
 }
 
+/// Selects whether a formatter emits ANSI escape codes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Ansi {
+    /// Always emit ANSI escape codes.
+    Always,
+    /// Never emit ANSI escape codes.
+    Never,
+    /// Emit ANSI escape codes only if the output target looks like it
+    /// supports them: it's a terminal, and the environment doesn't
+    /// explicitly disable color (see [`env_allows_color`]).
+    Auto,
+}
+
+impl Ansi {
+    /// Resolves this mode to a concrete `is_ansi` flag, given whether the
+    /// output target is a terminal.
+    pub(crate) fn is_ansi(self, is_terminal: bool) -> bool {
+        match self {
+            Ansi::Always => true,
+            Ansi::Never => false,
+            Ansi::Auto => is_terminal && env_allows_color(),
+        }
+    }
+}
+
+/// Checks the `NO_COLOR`, `CLICOLOR`, and `CLICOLOR_FORCE` environment
+/// variables to determine whether color output is allowed, following the
+/// conventions at <https://no-color.org> and
+/// <https://bixense.com/clicolors/>.
+fn env_allows_color() -> bool {
+    if env_is_set("CLICOLOR_FORCE") {
+        return true;
+    }
+    if env_is_set("NO_COLOR") {
+        return false;
+    }
+    if let Ok(value) = std::env::var("CLICOLOR") {
+        return value != "0";
+    }
+    true
+}
+
+fn env_is_set(key: &str) -> bool {
+    std::env::var_os(key).map_or(false, |v| v != "0")
+}
+
+/// A parsed, introspectable set of text attributes and an optional
+/// foreground/background [`Color`], used to describe a style for
+/// [`StyleRemap`].
+///
+/// Unlike [`Style`], which only forwards attribute calls onto an
+/// owo_colors builder, this type records exactly which attributes are set
+/// so that it can be compared against a style parsed out of an incoming
+/// SGR escape sequence.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct StyleAttributes {
+    pub bold: bool,
+    pub dimmed: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub blink: bool,
+    pub reversed: bool,
+    pub hidden: bool,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+impl StyleAttributes {
+    fn from_sgr_codes(codes: &[u32]) -> Self {
+        let mut attrs = Self::default();
+        let mut i = 0;
+        while i < codes.len() {
+            match codes[i] {
+                0 => attrs = Self::default(),
+                1 => attrs.bold = true,
+                2 => attrs.dimmed = true,
+                3 => attrs.italic = true,
+                4 => attrs.underline = true,
+                5 | 6 => attrs.blink = true,
+                7 => attrs.reversed = true,
+                8 => attrs.hidden = true,
+                9 => attrs.strikethrough = true,
+                22 => {
+                    attrs.bold = false;
+                    attrs.dimmed = false;
+                }
+                23 => attrs.italic = false,
+                24 => attrs.underline = false,
+                25 => attrs.blink = false,
+                27 => attrs.reversed = false,
+                28 => attrs.hidden = false,
+                29 => attrs.strikethrough = false,
+                30..=37 => attrs.fg = Some(basic_color_from_sgr(codes[i] - 30)),
+                38 => {
+                    if let Some((color, consumed)) = extended_color(&codes[i + 1..]) {
+                        attrs.fg = Some(color);
+                        i += consumed;
+                    }
+                }
+                39 => attrs.fg = None,
+                40..=47 => attrs.bg = Some(basic_color_from_sgr(codes[i] - 40)),
+                48 => {
+                    if let Some((color, consumed)) = extended_color(&codes[i + 1..]) {
+                        attrs.bg = Some(color);
+                        i += consumed;
+                    }
+                }
+                49 => attrs.bg = None,
+                _ => {}
+            }
+            i += 1;
+        }
+        attrs
+    }
+
+    fn to_sgr_codes(self) -> Vec<u32> {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(1);
+        }
+        if self.dimmed {
+            codes.push(2);
+        }
+        if self.italic {
+            codes.push(3);
+        }
+        if self.underline {
+            codes.push(4);
+        }
+        if self.blink {
+            codes.push(5);
+        }
+        if self.reversed {
+            codes.push(7);
+        }
+        if self.hidden {
+            codes.push(8);
+        }
+        if self.strikethrough {
+            codes.push(9);
+        }
+        if let Some(color) = self.fg {
+            codes.extend(color_sgr_params(color, 30, 38));
+        }
+        if let Some(color) = self.bg {
+            codes.extend(color_sgr_params(color, 40, 48));
+        }
+        codes
+    }
+}
+
+/// Parses the parameters following a `38`/`48` extended-color introducer
+/// (i.e. everything after the `38`/`48` itself): `5;n` selects the xterm
+/// 256-color palette index `n`, `2;r;g;b` selects a truecolor RGB value.
+/// Returns the parsed color and how many of `params` it consumed, so the
+/// caller can skip over them rather than misreading them as unrelated SGR
+/// codes.
+fn extended_color(params: &[u32]) -> Option<(Color, usize)> {
+    match *params {
+        [5, n, ..] => Some((Color::Ansi256(n as u8), 2)),
+        [2, r, g, b, ..] => Some((Color::Rgb(r as u8, g as u8, b as u8), 4)),
+        _ => None,
+    }
+}
+
+fn basic_color_from_sgr(n: u32) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Returns the SGR parameter(s) that select `color` as a foreground (if
+/// `basic_base` is 30 and `extended_code` is 38) or background (40/48)
+/// color: a single basic-color code, or the extended-color introducer
+/// followed by its `5;n` (256-color) or `2;r;g;b` (truecolor) parameters.
+fn color_sgr_params(color: Color, basic_base: u32, extended_code: u32) -> Vec<u32> {
+    match color {
+        Color::Black => vec![basic_base],
+        Color::Red => vec![basic_base + 1],
+        Color::Green => vec![basic_base + 2],
+        Color::Yellow => vec![basic_base + 3],
+        Color::Blue => vec![basic_base + 4],
+        Color::Magenta | Color::Purple => vec![basic_base + 5],
+        Color::Cyan => vec![basic_base + 6],
+        Color::White => vec![basic_base + 7],
+        Color::Ansi256(n) => vec![extended_code, 5, u32::from(n)],
+        Color::Rgb(r, g, b) => vec![extended_code, 2, u32::from(r), u32::from(g), u32::from(b)],
+    }
+}
+
+/// A table of `(from, to)` style pairs used to normalize ANSI escape
+/// sequences that are already present in recorded field values (for
+/// example, a string logged by a library that colors its own output) to
+/// match the subscriber's configured theme.
+///
+/// SGR sequences that match no rule are left untouched, unless
+/// [`strip_unknown`] is set, in which case they are dropped. Non-SGR control
+/// sequences and partial or malformed escapes are always left untouched.
+///
+/// [`strip_unknown`]: StyleRemap::strip_unknown
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct StyleRemap {
+    rules: Vec<(StyleAttributes, StyleAttributes)>,
+    strip_unknown: bool,
+}
+
+impl StyleRemap {
+    /// Returns a new, empty `StyleRemap` that leaves all input unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that rewrites any SGR sequence matching `from` to `to`.
+    pub fn map(mut self, from: StyleAttributes, to: StyleAttributes) -> Self {
+        self.rules.push((from, to));
+        self
+    }
+
+    /// Sets whether SGR sequences that don't match any configured rule are
+    /// dropped entirely, rather than passed through unchanged.
+    ///
+    /// Defaults to `false`.
+    pub fn strip_unknown(mut self, strip_unknown: bool) -> Self {
+        self.strip_unknown = strip_unknown;
+        self
+    }
+
+    /// Rewrites recognized SGR sequences in `input` according to the
+    /// configured rules.
+    pub fn apply(&self, input: &str) -> String {
+        let bytes = input.as_bytes();
+        let mut out = String::with_capacity(input.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0x1b && bytes.get(i + 1) == Some(&b'[') {
+                let mut j = i + 2;
+                let mut well_formed = true;
+                while j < bytes.len() && bytes[j] != b'm' {
+                    if !(bytes[j] == b';' || bytes[j].is_ascii_digit()) {
+                        well_formed = false;
+                        break;
+                    }
+                    j += 1;
+                }
+                if well_formed && j < bytes.len() {
+                    let body = &input[i + 2..j];
+                    let codes: Vec<u32> = if body.is_empty() {
+                        vec![0]
+                    } else {
+                        body.split(';').filter_map(|code| code.parse().ok()).collect()
+                    };
+                    let parsed = StyleAttributes::from_sgr_codes(&codes);
+                    match self.rules.iter().find(|(from, _)| *from == parsed) {
+                        Some((_, to)) => {
+                            let mapped = to.to_sgr_codes();
+                            if mapped.is_empty() {
+                                out.push_str("\x1b[0m");
+                            } else {
+                                let parts: Vec<String> =
+                                    mapped.iter().map(u32::to_string).collect();
+                                out.push_str(&format!("\x1b[{}m", parts.join(";")));
+                            }
+                        }
+                        None if !self.strip_unknown => out.push_str(&input[i..=j]),
+                        None => {}
+                    }
+                    i = j + 1;
+                    continue;
+                }
+            }
+
+            // Not a well-formed SGR sequence (a non-SGR control sequence, a
+            // partial escape, or plain text) -- copy it through untouched.
+            let ch = input[i..].chars().next().expect("i < bytes.len()");
+            out.push(ch);
+            i += ch.len_utf8();
+        }
+        out
+    }
+}
+
+/// A customizable color palette for the fmt formatters.
+///
+/// One [`Color`] is assigned per [`Level`], plus optional colors for a few
+/// other semantic roles (the event target, span names, and field keys).
+/// Start from [`Palette::default()`] (the colors used prior to this type
+/// existing) and override only the roles you care about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    trace: Color,
+    debug: Color,
+    info: Color,
+    warn: Color,
+    error: Color,
+    target: Option<Color>,
+    span_name: Option<Color>,
+    field_key: Option<Color>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            trace: Color::Purple,
+            debug: Color::Blue,
+            info: Color::Green,
+            warn: Color::Yellow,
+            error: Color::Red,
+            target: None,
+            span_name: None,
+            field_key: None,
+        }
+    }
+}
+
+impl Palette {
+    /// Returns a new `Palette` with [`Palette::default`]'s colors, for
+    /// overriding only the roles you care about.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color used for a given [`Level`].
+    pub fn with_level(mut self, level: Level, color: Color) -> Self {
+        match level {
+            Level::TRACE => self.trace = color,
+            Level::DEBUG => self.debug = color,
+            Level::INFO => self.info = color,
+            Level::WARN => self.warn = color,
+            Level::ERROR => self.error = color,
+        }
+        self
+    }
+
+    /// Sets the color used for the event's target.
+    ///
+    /// Defaults to the ambient style (no override).
+    pub fn with_target(mut self, color: Color) -> Self {
+        self.target = Some(color);
+        self
+    }
+
+    /// Sets the color used for span names.
+    ///
+    /// Defaults to the ambient style (no override).
+    pub fn with_span_name(mut self, color: Color) -> Self {
+        self.span_name = Some(color);
+        self
+    }
+
+    /// Sets the color used for field keys.
+    ///
+    /// Defaults to the ambient style (no override).
+    pub fn with_field_key(mut self, color: Color) -> Self {
+        self.field_key = Some(color);
+        self
+    }
+
+    fn color_for_level(&self, level: &Level) -> Color {
+        match *level {
+            Level::TRACE => self.trace,
+            Level::DEBUG => self.debug,
+            Level::INFO => self.info,
+            Level::WARN => self.warn,
+            Level::ERROR => self.error,
+        }
+    }
+
+    pub(crate) fn target_color(&self) -> Option<Color> {
+        self.target
+    }
+
+    pub(crate) fn span_name_color(&self) -> Option<Color> {
+        self.span_name
+    }
+
+    pub(crate) fn field_key_color(&self) -> Option<Color> {
+        self.field_key
+    }
+}
+
 #[cfg(feature = "ansi")]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct Style {
@@ -21,6 +411,12 @@ impl Style {
         }
     }
 
+    /// Constructs a new `Style`, resolving its `is_ansi` flag from the given
+    /// [`Ansi`] mode and whether the output target is a terminal.
+    pub(crate) fn from_ansi_mode(mode: Ansi, is_terminal: bool) -> Self {
+        Self::new(mode.is_ansi(is_terminal))
+    }
+
     pub(crate) fn with_ansi(self, is_ansi: bool) -> Self {
         Style { is_ansi, ..self }
     }
@@ -50,19 +446,89 @@ impl Style {
         }
     }
 
-    pub(crate) fn level_color(self, level: &Level) -> Self {
-        let inner = match *level {
-            Level::TRACE => self.inner.purple(),
-            Level::DEBUG => self.inner.blue(),
-            Level::INFO => self.inner.green(),
-            Level::WARN => self.inner.yellow(),
-            Level::ERROR => self.inner.red(),
+    pub(crate) fn underline(self) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.underline(),
+        }
+    }
+
+    pub(crate) fn strikethrough(self) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.strikethrough(),
+        }
+    }
+
+    pub(crate) fn blink(self) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.blink(),
+        }
+    }
+
+    pub(crate) fn reversed(self) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.reversed(),
+        }
+    }
+
+    pub(crate) fn hidden(self) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.hidden(),
+        }
+    }
+
+    pub(crate) fn fg(self, color: Color) -> Self {
+        let inner = match color {
+            Color::Black => self.inner.black(),
+            Color::Red => self.inner.red(),
+            Color::Green => self.inner.green(),
+            Color::Yellow => self.inner.yellow(),
+            Color::Blue => self.inner.blue(),
+            Color::Magenta => self.inner.magenta(),
+            Color::Purple => self.inner.purple(),
+            Color::Cyan => self.inner.cyan(),
+            Color::White => self.inner.white(),
+            Color::Ansi256(n) => return self.fg_ansi256(n),
+            Color::Rgb(r, g, b) => return self.fg_rgb(r, g, b),
         };
         Style {
-            is_ansi: true,
+            is_ansi: self.is_ansi,
             inner,
         }
     }
+
+    /// Sets the foreground color to an arbitrary 24-bit RGB value.
+    ///
+    /// The truecolor escape sequence is emitted as is: the terminal's color
+    /// support is not checked, and no fallback to a basic ANSI color is
+    /// made on terminals that only support 16 colors. Nothing is emitted
+    /// when ANSI output is disabled.
+    pub(crate) fn fg_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.color(Rgb(r, g, b)),
+        }
+    }
+
+    /// Sets the foreground color to the given xterm 256-color code.
+    pub(crate) fn fg_ansi256(self, color: u8) -> Self {
+        Style {
+            is_ansi: self.is_ansi,
+            inner: self.inner.color(XtermColors::from(color)),
+        }
+    }
+
+    pub(crate) fn level_color(self, level: &Level, palette: &Palette) -> Self {
+        let styled = self.fg(palette.color_for_level(level));
+        Style {
+            is_ansi: true,
+            inner: styled.inner,
+        }
+    }
 }
 
 #[cfg(feature = "ansi")]
@@ -86,6 +552,10 @@ impl Style {
         Style
     }
 
+    pub fn from_ansi_mode(_mode: Ansi, _is_terminal: bool) -> Self {
+        Style
+    }
+
     pub fn with_ansi(self, is_ansi: bool) -> Self {
         self
     }
@@ -105,6 +575,42 @@ impl Style {
     pub fn italic(self) -> Self {
         self
     }
+
+    pub fn underline(self) -> Self {
+        self
+    }
+
+    pub fn strikethrough(self) -> Self {
+        self
+    }
+
+    pub fn blink(self) -> Self {
+        self
+    }
+
+    pub fn reversed(self) -> Self {
+        self
+    }
+
+    pub fn hidden(self) -> Self {
+        self
+    }
+
+    pub fn fg(self, _color: Color) -> Self {
+        self
+    }
+
+    pub fn fg_rgb(self, _r: u8, _g: u8, _b: u8) -> Self {
+        self
+    }
+
+    pub fn fg_ansi256(self, _color: u8) -> Self {
+        self
+    }
+
+    pub fn level_color(self, _level: &Level, _palette: &Palette) -> Self {
+        self
+    }
 }
 #[cfg(not(feature = "ansi"))]
 impl StylePainter for Style {
@@ -143,3 +649,54 @@ impl_fmt! {
     fmt::Octal,
     fmt::Pointer,
 }
+
+#[cfg(all(test, feature = "ansi"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_256_color_as_a_single_unit() {
+        let attrs = StyleAttributes::from_sgr_codes(&[38, 5, 208]);
+        assert_eq!(attrs.fg, Some(Color::Ansi256(208)));
+        assert!(!attrs.blink, "the `5` selector must not be read as SGR blink");
+    }
+
+    #[test]
+    fn parses_truecolor_as_a_single_unit() {
+        let attrs = StyleAttributes::from_sgr_codes(&[38, 2, 30, 144, 255]);
+        assert_eq!(attrs.fg, Some(Color::Rgb(30, 144, 255)));
+        assert_eq!(attrs.bg, None, "no truecolor component may be read as a background color");
+    }
+
+    #[test]
+    fn parses_extended_background_colors() {
+        let attrs = StyleAttributes::from_sgr_codes(&[1, 48, 5, 22]);
+        assert!(attrs.bold);
+        assert_eq!(attrs.bg, Some(Color::Ansi256(22)));
+    }
+
+    #[test]
+    fn round_trips_extended_colors_through_to_sgr_codes() {
+        let attrs = StyleAttributes {
+            fg: Some(Color::Rgb(30, 144, 255)),
+            ..StyleAttributes::default()
+        };
+        assert_eq!(
+            StyleAttributes::from_sgr_codes(&attrs.to_sgr_codes()),
+            attrs
+        );
+    }
+
+    #[test]
+    fn style_remap_apply_leaves_unmatched_truecolor_sequences_untouched() {
+        let remap = StyleRemap::new();
+        let input = "\x1b[38;2;30;144;255mhello\x1b[0m";
+        assert_eq!(remap.apply(input), input);
+    }
+
+    #[test]
+    fn style_remap_apply_can_strip_unmatched_truecolor_sequences() {
+        let remap = StyleRemap::new().strip_unknown(true);
+        assert_eq!(remap.apply("\x1b[38;2;30;144;255mhello\x1b[0m"), "hello");
+    }
+}